@@ -0,0 +1,31 @@
+//! Traits used to implement shapes that can be drawn.
+
+use lyon_tessellation::path::path::Builder;
+
+/// Whether a shape's tessellated geometry is meant to be filled or stroked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawMode {
+    /// The shape is an area that should be filled.
+    Fill,
+    /// The shape is an outline that should be stroked.
+    Stroke,
+}
+
+/// A shape whose geometry can be tessellated by lyon.
+///
+/// The structs defined in [`crate::shapes`] implement this trait. You can
+/// also implement it for your own shapes.
+pub trait Geometry {
+    /// Adds the geometry of the shape to the given builder.
+    fn add_geometry(&self, b: &mut Builder);
+
+    /// The draw mode this shape defaults to when the caller doesn't specify
+    /// one, or `None` when either mode is equally sensible.
+    ///
+    /// This lets the spawning API honor a shape's own intent instead of,
+    /// for example, feeding an open polyline to a fill tessellator and
+    /// getting garbage back.
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        None
+    }
+}
@@ -4,12 +4,16 @@
 //! [`Geometry`](crate::geometry::Geometry) trait. You can also implement
 //! the trait for your own shapes.
 
-use crate::{geometry::Geometry, utils::Convert};
+use crate::{
+    geometry::{DrawMode, Geometry},
+    utils::Convert,
+};
 use bevy::math::Vec2;
 use lyon_tessellation::{
-    math::{point, Angle, Point, Rect, Size},
+    math::{Angle, Point, Rect, Size},
     path::{path::Builder, traits::PathBuilder, Polygon as LyonPolygon, Winding},
 };
+use std::f32::consts::{FRAC_PI_2, PI};
 
 /// Defines where the origin, or pivot of the `Rectangle` should be positioned.
 #[allow(missing_docs)]
@@ -29,12 +33,47 @@ impl Default for RectangleOrigin {
     }
 }
 
+/// The radius of each of the four corners of a [`Rectangle`].
+///
+/// A corner with a radius of `0.0` stays sharp; anything larger is replaced
+/// with a circular fillet in `add_geometry`.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderRadii {
+    pub bottom_left: f32,
+    pub bottom_right: f32,
+    pub top_right: f32,
+    pub top_left: f32,
+}
+
+impl BorderRadii {
+    /// Creates a `BorderRadii` with the same radius on all four corners.
+    pub fn single(radius: f32) -> Self {
+        Self {
+            bottom_left: radius,
+            bottom_right: radius,
+            top_right: radius,
+            top_left: radius,
+        }
+    }
+
+    /// Returns `true` if every corner has a non-positive radius, i.e. the
+    /// rectangle has no rounding to apply.
+    fn is_zero(&self) -> bool {
+        self.bottom_left <= 0.0
+            && self.bottom_right <= 0.0
+            && self.top_right <= 0.0
+            && self.top_left <= 0.0
+    }
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Rectangle {
     pub width: f32,
     pub height: f32,
     pub origin: RectangleOrigin,
+    pub corner_radii: BorderRadii,
 }
 
 impl Default for Rectangle {
@@ -43,6 +82,7 @@ impl Default for Rectangle {
             width: 1.0,
             height: 1.0,
             origin: RectangleOrigin::default(),
+            corner_radii: BorderRadii::default(),
         }
     }
 }
@@ -60,10 +100,33 @@ impl Geometry for Rectangle {
             }
         };
 
-        b.add_rectangle(
-            &Rect::new(origin, Size::new(self.width, self.height)),
-            Winding::Positive,
-        );
+        if self.corner_radii.is_zero() {
+            b.add_rectangle(
+                &Rect::new(origin, Size::new(self.width, self.height)),
+                Winding::Positive,
+            );
+            return;
+        }
+
+        let origin = Vec2::new(origin.x, origin.y);
+        let points = [
+            origin,
+            origin + Vec2::new(self.width, 0.0),
+            origin + Vec2::new(self.width, self.height),
+            origin + Vec2::new(0.0, self.height),
+        ];
+        let radii = [
+            self.corner_radii.bottom_left,
+            self.corner_radii.bottom_right,
+            self.corner_radii.top_right,
+            self.corner_radii.top_left,
+        ];
+
+        add_rounded_polygon(b, &points, &radii, true);
+    }
+
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
     }
 }
 
@@ -87,6 +150,58 @@ impl Geometry for Circle {
     fn add_geometry(&self, b: &mut Builder) {
         b.add_circle(self.center.convert(), self.radius, Winding::Positive);
     }
+
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
+    }
+}
+
+/// A ring: an outer circle with a concentric circular hole.
+///
+/// The outer circle is added with `Winding::Positive` and the inner circle
+/// with `Winding::Negative`, so lyon's even-odd fill tessellates only the
+/// area between them. This is the common case users hit when drawing
+/// gauges, pie-chart borders, and donut colliders.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Annulus {
+    pub center: Vec2,
+    pub outer_radius: f32,
+    pub inner_radius: f32,
+}
+
+impl Default for Annulus {
+    fn default() -> Self {
+        Self {
+            center: Vec2::zero(),
+            outer_radius: 1.0,
+            inner_radius: 0.5,
+        }
+    }
+}
+
+impl Annulus {
+    /// The `(radius, winding)` pairs passed to `add_circle` for the outer
+    /// and inner circle, in that order.
+    fn circles(&self) -> [(f32, Winding); 2] {
+        [
+            (self.outer_radius, Winding::Positive),
+            (self.inner_radius, Winding::Negative),
+        ]
+    }
+}
+
+impl Geometry for Annulus {
+    fn add_geometry(&self, b: &mut Builder) {
+        let center = self.center.convert();
+        for (radius, winding) in self.circles() {
+            b.add_circle(center, radius, winding);
+        }
+    }
+
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
+    }
 }
 
 #[allow(missing_docs)]
@@ -114,6 +229,77 @@ impl Geometry for Ellipse {
             Winding::Positive,
         );
     }
+
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
+    }
+}
+
+/// A rhombus (diamond), defined by the half-lengths of its two diagonals.
+///
+/// This is the natural primitive for isometric or diamond tile grids, where
+/// building the four vertices of a [`Polygon`] by hand every time is a
+/// common annoyance.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rhombus {
+    pub horizontal_radius: f32,
+    pub vertical_radius: f32,
+    pub center: Vec2,
+}
+
+impl Default for Rhombus {
+    fn default() -> Self {
+        Self {
+            horizontal_radius: 1.0,
+            vertical_radius: 1.0,
+            center: Vec2::zero(),
+        }
+    }
+}
+
+impl Rhombus {
+    /// Creates a `Rhombus` from its side length and the angle, in radians,
+    /// between a side and the horizontal diagonal.
+    pub fn from_side_and_angle(side_length: f32, angle: f32) -> Self {
+        Self {
+            horizontal_radius: side_length * angle.cos(),
+            vertical_radius: side_length * angle.sin(),
+            center: Vec2::zero(),
+        }
+    }
+}
+
+impl Rhombus {
+    /// The four vertices, starting at the right-hand end of the horizontal
+    /// diagonal and proceeding clockwise: right, bottom, left, top.
+    fn vertices(&self) -> [Vec2; 4] {
+        [
+            self.center + Vec2::new(self.horizontal_radius, 0.0),
+            self.center + Vec2::new(0.0, -self.vertical_radius),
+            self.center + Vec2::new(-self.horizontal_radius, 0.0),
+            self.center + Vec2::new(0.0, self.vertical_radius),
+        ]
+    }
+}
+
+impl Geometry for Rhombus {
+    fn add_geometry(&self, b: &mut Builder) {
+        let points = self
+            .vertices()
+            .iter()
+            .map(|p| p.convert())
+            .collect::<Vec<Point>>();
+
+        b.add_polygon(LyonPolygon {
+            points: points.as_slice(),
+            closed: true,
+        });
+    }
+
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
+    }
 }
 
 #[allow(missing_docs)]
@@ -121,6 +307,7 @@ impl Geometry for Ellipse {
 pub struct Polygon {
     pub points: Vec<Vec2>,
     pub closed: bool,
+    pub corner_radius: f32,
 }
 
 impl Default for Polygon {
@@ -128,23 +315,133 @@ impl Default for Polygon {
         Self {
             points: Vec::new(),
             closed: true,
+            corner_radius: 0.0,
         }
     }
 }
 
 impl Geometry for Polygon {
+    fn add_geometry(&self, b: &mut Builder) {
+        if self.corner_radius <= 0.0 || self.points.len() < 3 {
+            let points = self
+                .points
+                .iter()
+                .map(|p| p.convert())
+                .collect::<Vec<Point>>();
+            let polygon: LyonPolygon<Point> = LyonPolygon {
+                points: points.as_slice(),
+                closed: self.closed,
+            };
+
+            b.add_polygon(polygon);
+            return;
+        }
+
+        let radii = vec![self.corner_radius; self.points.len()];
+        add_rounded_polygon(b, &self.points, &radii, self.closed);
+    }
+
+    /// An open `Polygon` is a stroke-only outline; a closed one is an area.
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(if self.closed {
+            DrawMode::Fill
+        } else {
+            DrawMode::Stroke
+        })
+    }
+}
+
+/// The error returned by [`ConvexPolygon::new`] when the given points do
+/// not form a convex polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvexPolygonError {
+    /// Fewer than three points were given.
+    NotEnoughPoints,
+    /// The turn direction changes between two consecutive vertices, which
+    /// means the polygon has a reflex (concave) angle.
+    NotConvex,
+}
+
+impl std::fmt::Display for ConvexPolygonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotEnoughPoints => write!(f, "a convex polygon needs at least three points"),
+            Self::NotConvex => write!(f, "the given points do not form a convex polygon"),
+        }
+    }
+}
+
+impl std::error::Error for ConvexPolygonError {}
+
+/// A closed polygon that is guaranteed to be convex.
+///
+/// Unlike the general, unchecked [`Polygon`], the convexity of a
+/// `ConvexPolygon` is validated once at construction time via
+/// [`ConvexPolygon::new`], so downstream tessellation and any future
+/// collider/extrusion code can fast-path on it.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexPolygon {
+    points: Vec<Vec2>,
+}
+
+impl ConvexPolygon {
+    /// Validates that `points` form a convex polygon and wraps them.
+    ///
+    /// The vertex list is treated as cyclic: each consecutive triple of
+    /// vertices `(a, b, c)`, wrapping around the end, must turn the same
+    /// way. The turn direction is the sign of the 2D cross product
+    /// `(b - a) x (c - b)`; a sign flip between two triples means the
+    /// polygon has a reflex angle. Collinear triples (a zero cross
+    /// product) are allowed.
+    pub fn new(points: Vec<Vec2>) -> Result<Self, ConvexPolygonError> {
+        if points.len() < 3 {
+            return Err(ConvexPolygonError::NotEnoughPoints);
+        }
+
+        let n = points.len();
+        let mut sign = 0.0_f32;
+        for i in 0..n {
+            let a = points[i];
+            let b = points[(i + 1) % n];
+            let c = points[(i + 2) % n];
+            let cross = (b - a).perp_dot(c - b);
+
+            if cross == 0.0 {
+                continue;
+            }
+            if sign == 0.0 {
+                sign = cross.signum();
+            } else if cross.signum() != sign {
+                return Err(ConvexPolygonError::NotConvex);
+            }
+        }
+
+        Ok(Self { points })
+    }
+
+    /// Returns the polygon's vertices.
+    pub fn points(&self) -> &[Vec2] {
+        &self.points
+    }
+}
+
+impl Geometry for ConvexPolygon {
     fn add_geometry(&self, b: &mut Builder) {
         let points = self
             .points
             .iter()
             .map(|p| p.convert())
             .collect::<Vec<Point>>();
-        let polygon: LyonPolygon<Point> = LyonPolygon {
+
+        b.add_polygon(LyonPolygon {
             points: points.as_slice(),
-            closed: self.closed,
-        };
+            closed: true,
+        });
+    }
 
-        b.add_polygon(polygon);
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
     }
 }
 
@@ -166,12 +463,17 @@ pub struct RegularPolygon {
     pub sides: usize,
     pub center: Vec2,
     pub feature: RegularPolygonFeature,
+    pub corner_radius: f32,
+    /// Additional rotation, in radians, applied on top of the default
+    /// flat-bottom orientation. Useful for e.g. pointing a triangle upward
+    /// or aligning a hexagon to a pointy-top tile grid.
+    pub rotation: f32,
 }
 
 impl RegularPolygon {
     /// Gets the radius of the polygon.
     fn radius(&self) -> f32 {
-        let ratio = std::f32::consts::PI / self.sides as f32;
+        let ratio = PI / self.sides as f32;
 
         match self.feature {
             RegularPolygonFeature::Radius(r) => r,
@@ -179,6 +481,26 @@ impl RegularPolygon {
             RegularPolygonFeature::SideLength(s) => s / (2.0 * ratio.sin()),
         }
     }
+
+    /// The polygon's vertices, starting from the one nearest `offset`
+    /// (`-internal / 2.0 + self.rotation`) and proceeding counterclockwise.
+    fn vertices(&self) -> Vec<Vec2> {
+        assert!(self.sides > 2, "Polygons must have at least 3 sides");
+        let n = self.sides as f32;
+        let radius = self.radius();
+        let internal = (n - 2.0) * PI / n;
+        let offset = -internal / 2.0 + self.rotation;
+
+        let mut points = Vec::with_capacity(self.sides);
+        let step = 2.0 * PI / n;
+        for i in 0..self.sides {
+            let cur_angle = (i as f32).mul_add(step, offset);
+            let x = radius.mul_add(cur_angle.cos(), self.center.x);
+            let y = radius.mul_add(cur_angle.sin(), self.center.y);
+            points.push(Vec2::new(x, y));
+        }
+        points
+    }
 }
 
 impl Default for RegularPolygon {
@@ -187,39 +509,35 @@ impl Default for RegularPolygon {
             sides: 3,
             center: Vec2::zero(),
             feature: RegularPolygonFeature::Radius(1.0),
+            corner_radius: 0.0,
+            rotation: 0.0,
         }
     }
 }
 
 impl Geometry for RegularPolygon {
     fn add_geometry(&self, b: &mut Builder) {
-        // -- Implementation details **PLEASE KEEP UPDATED** --
-        // - `step`: angle between two vertices.
-        // - `internal`: internal angle of the polygon.
-        // - `offset`: bias to make the shape lay flat on a line parallel to the x-axis.
+        let points = self.vertices();
 
-        use std::f32::consts::PI;
-        assert!(self.sides > 2, "Polygons must have at least 3 sides");
-        let n = self.sides as f32;
-        let radius = self.radius();
-        let internal = (n - 2.0) * PI / n;
-        let offset = -internal / 2.0;
+        if self.corner_radius <= 0.0 {
+            let points = points.iter().map(|p| p.convert()).collect::<Vec<Point>>();
+            let polygon = LyonPolygon {
+                points: points.as_slice(),
+                closed: true,
+            };
 
-        let mut points = Vec::with_capacity(self.sides);
-        let step = 2.0 * PI / n;
-        for i in 0..self.sides {
-            let cur_angle = (i as f32).mul_add(step, offset);
-            let x = radius.mul_add(cur_angle.cos(), self.center.x);
-            let y = radius.mul_add(cur_angle.sin(), self.center.y);
-            points.push(point(x, y));
+            b.add_polygon(polygon);
+            return;
         }
 
-        let polygon = LyonPolygon {
-            points: points.as_slice(),
-            closed: true,
-        };
+        // All corners of a regular polygon are congruent, so a single radius
+        // applies uniformly to every vertex.
+        let radii = vec![self.corner_radius; points.len()];
+        add_rounded_polygon(b, &points, &radii, true);
+    }
 
-        b.add_polygon(polygon);
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Fill)
     }
 }
 
@@ -235,4 +553,317 @@ impl Geometry for Line {
             closed: false,
         });
     }
+
+    /// A `Line` is an open segment, so it is stroke-only: feeding it to a
+    /// fill tessellator produces garbage.
+    fn intended_draw_mode(&self) -> Option<DrawMode> {
+        Some(DrawMode::Stroke)
+    }
+}
+
+/// The circular fillet that replaces a single polygon vertex once rounded.
+///
+/// `start` is the tangent point where the fillet meets the incoming edge;
+/// `radius` is `0.0` for a vertex that stays sharp (either because it was
+/// given no radius, or because it has no well-defined fillet, e.g. a
+/// polygon endpoint or collinear neighbours).
+struct Fillet {
+    start: Vec2,
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    sweep_angle: f32,
+}
+
+impl Fillet {
+    fn sharp(vertex: Vec2) -> Self {
+        Self {
+            start: vertex,
+            center: vertex,
+            radius: 0.0,
+            start_angle: 0.0,
+            sweep_angle: 0.0,
+        }
+    }
+}
+
+/// Computes the fillet for the vertex at `points[i]`, given its neighbours.
+///
+/// With `u1` and `u2` the unit directions from the vertex towards its
+/// neighbours and `phi` half the angle between them, the fillet's tangent
+/// points lie at distance `d = radius / tan(phi)` along each edge. The
+/// tangent offset `d` is clamped so it never exceeds half the length of
+/// either adjacent edge, and the resulting radius is recomputed from the
+/// clamped `d` so the arc stays tangent to both edges.
+fn fillet_at(points: &[Vec2], i: usize, radius: f32, closed: bool) -> Fillet {
+    let n = points.len();
+
+    if radius <= 0.0 || (!closed && (i == 0 || i == n - 1)) {
+        return Fillet::sharp(points[i]);
+    }
+
+    let prev = points[(i + n - 1) % n];
+    let curr = points[i];
+    let next = points[(i + 1) % n];
+
+    let to_prev = prev - curr;
+    let to_next = next - curr;
+    let dist_prev = to_prev.length();
+    let dist_next = to_next.length();
+    if dist_prev <= f32::EPSILON || dist_next <= f32::EPSILON {
+        return Fillet::sharp(curr);
+    }
+
+    let u1 = to_prev / dist_prev;
+    let u2 = to_next / dist_next;
+
+    let phi = u1.dot(u2).clamp(-1.0, 1.0).acos() / 2.0;
+    if phi <= f32::EPSILON || phi >= FRAC_PI_2 {
+        return Fillet::sharp(curr);
+    }
+
+    let max_d = (dist_prev / 2.0).min(dist_next / 2.0);
+    let d = (radius / phi.tan()).min(max_d);
+    let r = d * phi.tan();
+
+    let start = curr + u1 * d;
+    let end = curr + u2 * d;
+    let center = curr + (u1 + u2).normalize() * (r / phi.sin());
+
+    let start_angle = (start.y - center.y).atan2(start.x - center.x);
+    let end_angle = (end.y - center.y).atan2(end.x - center.x);
+    let mut sweep_angle = end_angle - start_angle;
+    if sweep_angle > PI {
+        sweep_angle -= 2.0 * PI;
+    } else if sweep_angle < -PI {
+        sweep_angle += 2.0 * PI;
+    }
+
+    Fillet {
+        start,
+        center,
+        radius: r,
+        start_angle,
+        sweep_angle,
+    }
+}
+
+/// Approximates the fillet's circular arc with one or more cubic Bezier
+/// segments and emits it, assuming the builder's pen is already at
+/// `fillet.start`.
+///
+/// A single cubic Bezier only approximates a circular arc well up to about
+/// a quarter turn; beyond that it visibly bulges or flattens. Sharp
+/// interior angles (e.g. a triangle's corners) routinely produce fillet
+/// sweeps approaching a half turn, so the arc is chunked into sub-arcs of
+/// at most `FRAC_PI_2` each.
+fn add_fillet_arc(b: &mut Builder, fillet: &Fillet) {
+    let segments = (fillet.sweep_angle.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+    let segment_sweep = fillet.sweep_angle / segments as f32;
+    let kappa = 4.0 / 3.0 * (segment_sweep / 4.0).tan();
+    let tangent_scale = kappa * fillet.radius;
+
+    let mut angle = fillet.start_angle;
+    let mut point = fillet.start;
+    for _ in 0..segments {
+        let next_angle = angle + segment_sweep;
+        let (sin0, cos0) = angle.sin_cos();
+        let (sin1, cos1) = next_angle.sin_cos();
+        let next_point = fillet.center + Vec2::new(cos1, sin1) * fillet.radius;
+
+        let ctrl1 = point + Vec2::new(-sin0, cos0) * tangent_scale;
+        let ctrl2 = next_point - Vec2::new(-sin1, cos1) * tangent_scale;
+        b.cubic_bezier_to(ctrl1.convert(), ctrl2.convert(), next_point.convert());
+
+        angle = next_angle;
+        point = next_point;
+    }
+}
+
+/// Builds a closed or open polygon path where each vertex is replaced by a
+/// circular fillet of the corresponding radius in `radii` (a radius of
+/// `0.0` keeps that vertex sharp).
+fn add_rounded_polygon(b: &mut Builder, points: &[Vec2], radii: &[f32], closed: bool) {
+    let n = points.len();
+    if n == 0 {
+        return;
+    }
+
+    let fillets: Vec<Fillet> = (0..n)
+        .map(|i| fillet_at(points, i, radii[i], closed))
+        .collect();
+
+    b.begin(fillets[0].start.convert());
+    let edges = if closed { n } else { n - 1 };
+    for i in 0..edges {
+        let fillet = &fillets[i];
+        if fillet.radius > 0.0 {
+            add_fillet_arc(b, fillet);
+        }
+        let next = &fillets[(i + 1) % n];
+        b.line_to(next.start.convert());
+    }
+    b.end(closed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: f32, b: f32, eps: f32) -> bool {
+        (a - b).abs() <= eps
+    }
+
+    #[test]
+    fn convex_polygon_accepts_convex_points() {
+        let square = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        assert!(ConvexPolygon::new(square).is_ok());
+    }
+
+    #[test]
+    fn convex_polygon_rejects_reflex_vertex() {
+        // A concave "dart": the third point pokes inward.
+        let dart = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        assert_eq!(
+            ConvexPolygon::new(dart).unwrap_err(),
+            ConvexPolygonError::NotConvex
+        );
+    }
+
+    #[test]
+    fn convex_polygon_allows_collinear_points() {
+        let square_with_a_midpoint = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.5, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        assert!(ConvexPolygon::new(square_with_a_midpoint).is_ok());
+    }
+
+    #[test]
+    fn convex_polygon_rejects_too_few_points() {
+        let points = vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)];
+        assert_eq!(
+            ConvexPolygon::new(points).unwrap_err(),
+            ConvexPolygonError::NotEnoughPoints
+        );
+    }
+
+    #[test]
+    fn fillet_square_corner_sweeps_a_right_angle() {
+        let square = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        let fillet = fillet_at(&square, 1, 0.2, true);
+
+        assert!(approx_eq(fillet.sweep_angle.abs(), FRAC_PI_2, 1e-4));
+        assert!(approx_eq(fillet.radius, 0.2, 1e-4));
+    }
+
+    #[test]
+    fn fillet_acute_corner_sweeps_more_than_a_right_angle() {
+        // An equilateral triangle's 60 degree interior angles need a fillet
+        // sweep of 120 degrees, well past what a single cubic Bezier can
+        // approximate accurately.
+        let triangle = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.5, 0.866_025_4),
+        ];
+        let fillet = fillet_at(&triangle, 0, 0.1, true);
+
+        assert!(fillet.radius > 0.0);
+        assert!(fillet.sweep_angle.abs() > FRAC_PI_2);
+    }
+
+    #[test]
+    fn fillet_radius_is_clamped_by_the_shorter_edge() {
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.1, 0.0),
+            Vec2::new(0.1, 1.0),
+        ];
+        let fillet = fillet_at(&points, 1, 1.0, true);
+
+        // The incoming edge is only 0.1 long, so the tangent offset (and
+        // hence the effective radius) is clamped to half of it.
+        assert!(fillet.radius < 1.0);
+        assert!(approx_eq((fillet.start - points[1]).length(), 0.05, 1e-4));
+    }
+
+    #[test]
+    fn annulus_winds_the_outer_and_inner_circle_oppositely() {
+        let annulus = Annulus {
+            center: Vec2::zero(),
+            outer_radius: 2.0,
+            inner_radius: 0.5,
+        };
+        let [outer, inner] = annulus.circles();
+
+        assert_eq!(outer, (2.0, Winding::Positive));
+        assert_eq!(inner, (0.5, Winding::Negative));
+    }
+
+    #[test]
+    fn rhombus_vertices_sit_at_the_diagonal_endpoints() {
+        let rhombus = Rhombus {
+            horizontal_radius: 2.0,
+            vertical_radius: 1.0,
+            center: Vec2::new(3.0, 4.0),
+        };
+
+        assert_eq!(
+            rhombus.vertices(),
+            [
+                Vec2::new(5.0, 4.0),
+                Vec2::new(3.0, 3.0),
+                Vec2::new(1.0, 4.0),
+                Vec2::new(3.0, 5.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn regular_polygon_rotation_turns_every_vertex_by_the_same_angle() {
+        let flat = RegularPolygon {
+            sides: 4,
+            feature: RegularPolygonFeature::Radius(1.0),
+            rotation: 0.0,
+            ..Default::default()
+        };
+        let rotated = RegularPolygon {
+            rotation: FRAC_PI_2,
+            ..flat
+        };
+
+        let flat_vertices = flat.vertices();
+        let rotated_vertices = rotated.vertices();
+
+        // Rotating every flat vertex by `FRAC_PI_2` around the origin should
+        // reproduce the rotated polygon's vertices exactly.
+        for (flat_vertex, rotated_vertex) in flat_vertices.iter().zip(&rotated_vertices) {
+            let expected = Vec2::new(
+                flat_vertex.x * FRAC_PI_2.cos() - flat_vertex.y * FRAC_PI_2.sin(),
+                flat_vertex.x * FRAC_PI_2.sin() + flat_vertex.y * FRAC_PI_2.cos(),
+            );
+            assert!(approx_eq(rotated_vertex.x, expected.x, 1e-4));
+            assert!(approx_eq(rotated_vertex.y, expected.y, 1e-4));
+        }
+    }
 }